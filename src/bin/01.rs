@@ -4,6 +4,7 @@ use std::str::FromStr;
 advent_of_code::solution!(1);
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct LocationList {
     left: Vec<u32>,
     right: Vec<u32>,
@@ -94,6 +95,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::solution::{time_parts, Solution};
 
     fn example_list() -> LocationList {
         LocationList {
@@ -110,6 +112,16 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let parsed = advent_of_code::template::read_file("examples", DAY)
+            .parse::<LocationList>()
+            .unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<LocationList>(&json).unwrap(), parsed);
+    }
+
     #[test]
     fn test_right_counts() {
         let mut expected = BTreeMap::new();
@@ -132,4 +144,19 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(31));
     }
+
+    #[test]
+    fn test_solution_trait() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(Solver::part_one(&input), Some("11".to_string()));
+        assert_eq!(Solver::part_two(&input), Some("31".to_string()));
+    }
+
+    #[test]
+    fn test_time_parts() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        let ((one, _), (two, _)) = time_parts::<Solver>(&input);
+        assert_eq!(one, Solver::part_one(&input));
+        assert_eq!(two, Solver::part_two(&input));
+    }
 }
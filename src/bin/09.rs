@@ -2,6 +2,8 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::str::FromStr;
 
+use advent_of_code::parse::parse_decimal_digit;
+
 advent_of_code::solution!(9);
 
 fn checksum(id: usize, start: usize, length: usize) -> usize {
@@ -12,6 +14,7 @@ fn checksum(id: usize, start: usize, length: usize) -> usize {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Record {
     id: Option<usize>,
     start: usize,
@@ -100,6 +103,7 @@ impl SpaceAllocator {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct DiskMap {
     records: Vec<Record>,
 }
@@ -181,22 +185,6 @@ impl DiskMap {
 #[derive(Debug, PartialEq)]
 struct ParseDiskMapError;
 
-const fn parse_digit(ch: char) -> Result<usize, ParseDiskMapError> {
-    match ch {
-        '0' => Ok(0),
-        '1' => Ok(1),
-        '2' => Ok(2),
-        '3' => Ok(3),
-        '4' => Ok(4),
-        '5' => Ok(5),
-        '6' => Ok(6),
-        '7' => Ok(7),
-        '8' => Ok(8),
-        '9' => Ok(9),
-        _ => Err(ParseDiskMapError),
-    }
-}
-
 impl FromStr for DiskMap {
     type Err = ParseDiskMapError;
 
@@ -208,7 +196,9 @@ impl FromStr for DiskMap {
         let mut file = true;
 
         for ch in input.trim().chars() {
-            let length = parse_digit(ch)?;
+            let length = parse_decimal_digit(ch)
+                .map(usize::from)
+                .ok_or(ParseDiskMapError)?;
             let id = if file {
                 Some(id.next().unwrap_or(0))
             } else {
@@ -396,6 +386,15 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let parsed =
+            DiskMap::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<DiskMap>(&json).unwrap(), parsed);
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
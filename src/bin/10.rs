@@ -2,6 +2,8 @@ use std::collections::BTreeSet;
 use std::collections::VecDeque;
 use std::str::FromStr;
 
+use advent_of_code::parse::parse_decimal_digit;
+
 advent_of_code::solution!(10);
 
 const GRID_SIZE: usize = 40;
@@ -134,22 +136,6 @@ impl Iterator for TrailMap {
 #[derive(Debug, PartialEq)]
 struct ParseTrailMapError;
 
-const fn parse_digit(ch: char) -> Result<u8, ParseTrailMapError> {
-    match ch {
-        '0' => Ok(0),
-        '1' => Ok(1),
-        '2' => Ok(2),
-        '3' => Ok(3),
-        '4' => Ok(4),
-        '5' => Ok(5),
-        '6' => Ok(6),
-        '7' => Ok(7),
-        '8' => Ok(8),
-        '9' => Ok(9),
-        _ => Err(ParseTrailMapError),
-    }
-}
-
 impl FromStr for TrailMap {
     type Err = ParseTrailMapError;
 
@@ -157,7 +143,7 @@ impl FromStr for TrailMap {
         let mut grid = [[None; GRID_SIZE]; GRID_SIZE];
         for (row, line) in input.lines().enumerate() {
             for (col, ch) in line.chars().enumerate() {
-                let digit = parse_digit(ch)?;
+                let digit = parse_decimal_digit(ch).ok_or(ParseTrailMapError)?;
                 grid[row][col] = Some(digit);
             }
         }
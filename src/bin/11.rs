@@ -125,6 +125,24 @@ pub fn part_two(input: &str) -> Option<u64> {
     })
 }
 
+#[must_use]
+pub fn solve(input: &str) -> (Option<u64>, Option<u64>) {
+    let Ok(mut stones) = StoneLine::from_str(input) else {
+        return (None, None);
+    };
+
+    for _ in 0..25 {
+        stones = stones.blink();
+    }
+    let after_25 = stones.len();
+
+    for _ in 25..75 {
+        stones = stones.blink();
+    }
+
+    (Some(after_25), Some(stones.len()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,4 +260,10 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(65_601_038_650_482));
     }
+
+    #[test]
+    fn test_solve() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(solve(&input), (part_one(&input), part_two(&input)));
+    }
 }
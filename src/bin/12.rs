@@ -183,6 +183,19 @@ pub fn part_two(input: &str) -> Option<usize> {
     })
 }
 
+#[must_use]
+pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
+    let Ok(farm) = Farm::from_str(input) else {
+        return (None, None);
+    };
+
+    let regions = farm.find_regions();
+    let fence = regions.iter().map(|r| r.area * r.sides.len()).sum();
+    let discounted = regions.iter().map(|r| r.area * r.distinct_sides()).sum();
+
+    (Some(fence), Some(discounted))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,4 +326,10 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(1206));
     }
+
+    #[test]
+    fn test_solve() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(solve(&input), (part_one(&input), part_two(&input)));
+    }
 }
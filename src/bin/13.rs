@@ -5,6 +5,7 @@ advent_of_code::solution!(13);
 type Point = (i64, i64);
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Machine {
     a: Point,
     b: Point,
@@ -41,6 +42,7 @@ impl Machine {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Arcade {
     machines: Vec<Machine>,
 }
@@ -151,6 +153,15 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let parsed =
+            Arcade::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<Arcade>(&json).unwrap(), parsed);
+    }
+
     #[test]
     fn test_win_prize() {
         let arcade = example_arcade();
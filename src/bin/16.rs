@@ -2,82 +2,13 @@ use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::str::FromStr;
 
+use advent_of_code::direction::{Direction, COMPASS};
+use advent_of_code::search::dijkstra;
+
 advent_of_code::solution!(16);
 
 const GRID_SIZE: usize = 140;
 
-const fn grid_add(lhs: usize, rhs: usize) -> Option<usize> {
-    let rv = lhs + rhs;
-    if rv >= GRID_SIZE {
-        None
-    } else {
-        Some(rv)
-    }
-}
-
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-const COMPASS: [Direction; 4] = [
-    Direction::North,
-    Direction::East,
-    Direction::South,
-    Direction::West,
-];
-
-impl Direction {
-    fn step_from(self, position: usize) -> Option<usize> {
-        let row = position / GRID_SIZE;
-        let col = position % GRID_SIZE;
-
-        let row = match self {
-            Self::North => row.checked_sub(1),
-            Self::South => grid_add(row, 1),
-            Self::West | Self::East => Some(row),
-        };
-        let row = row?;
-
-        let col = match self {
-            Self::West => col.checked_sub(1),
-            Self::East => grid_add(col, 1),
-            Self::North | Self::South => Some(col),
-        };
-        col.map(|col| (row * GRID_SIZE) + col)
-    }
-
-    const fn opposite(self) -> Self {
-        match self {
-            Self::North => Self::South,
-            Self::East => Self::West,
-            Self::South => Self::North,
-            Self::West => Self::East,
-        }
-    }
-
-    const fn turn_left(self) -> Self {
-        match self {
-            Self::North => Self::West,
-            Self::East => Self::North,
-            Self::South => Self::East,
-            Self::West => Self::South,
-        }
-    }
-
-    const fn turn_right(self) -> Self {
-        match self {
-            Self::North => Self::East,
-            Self::East => Self::South,
-            Self::South => Self::West,
-            Self::West => Self::North,
-        }
-    }
-}
-
 #[derive(Debug, Eq, PartialEq)]
 struct ReindeerState {
     score: u32,
@@ -101,28 +32,14 @@ impl ReindeerState {
         })
     }
 
-    fn next_states(&self, maze: &Maze) -> impl Iterator<Item = Self> + use<'_> {
-        let empty: Box<dyn Iterator<Item = Self>> = Box::new(std::iter::empty());
-        let Some(position) = self.facing.step_from(self.position) else {
-            return empty;
-        };
-        if !maze.grid[position] {
-            return empty;
-        };
-
-        Box::new(
-            [
-                (self.facing, 1),
-                (self.facing.turn_left(), 1001),
-                (self.facing.turn_right(), 1001),
-            ]
-            .into_iter()
-            .map(move |(facing, extra_score)| Self {
-                score: self.score + extra_score,
+    fn next_states<'a>(&self, maze: &'a Maze) -> impl Iterator<Item = Self> + use<'a> {
+        let score = self.score;
+        maze.moves(self.position, self.facing)
+            .map(move |((position, facing), cost)| Self {
+                score: score + cost,
                 position,
                 facing,
-            }),
-        )
+            })
     }
 
     fn previous_states(&self) -> impl Iterator<Item = Self> + use<'_> {
@@ -137,7 +54,7 @@ impl ReindeerState {
         ]
         .into_iter()
         .filter_map(move |(step, facing, less_score)| {
-            let position = step.step_from(self.position);
+            let position = step.step(self.position, GRID_SIZE, GRID_SIZE);
             position.map(|position| Self {
                 score: self.score.saturating_sub(less_score),
                 position,
@@ -245,26 +162,38 @@ struct Maze {
 }
 
 impl Maze {
-    fn best_path(&self) -> Option<u32> {
-        let mut queue = ReindeerStateQueue::new();
-        for state in ReindeerState::initial(self) {
-            queue.push(state);
-        }
-
-        while let Some(state) = queue.pop() {
-            if state.position == self.end {
-                return Some(state.score);
-            }
-
-            for next in state.next_states(self) {
-                queue.push(next);
-            }
-        }
+    fn moves(
+        &self,
+        position: usize,
+        facing: Direction,
+    ) -> impl Iterator<Item = ((usize, Direction), u32)> + use<'_> {
+        facing
+            .step(position, GRID_SIZE, GRID_SIZE)
+            .filter(|&ahead| self.grid[ahead])
+            .into_iter()
+            .flat_map(move |ahead| {
+                [
+                    (facing, 1),
+                    (facing.turn_left(), 1001),
+                    (facing.turn_right(), 1001),
+                ]
+                .map(|(facing, cost)| ((ahead, facing), cost))
+            })
+    }
 
-        None
+    fn best_path(&self) -> Option<u32> {
+        dijkstra(
+            ReindeerState::initial(self).map(|state| ((state.position, state.facing), state.score)),
+            |&(position, facing)| self.moves(position, facing),
+            |&(position, _)| position == self.end,
+        )
     }
 
     fn spaces_in_best_paths(&self) -> u32 {
+        self.best_paths().1
+    }
+
+    fn best_paths(&self) -> (Option<u32>, u32) {
         let mut best = u32::MAX;
         let mut queue = ReindeerStateQueue::new();
         for state in ReindeerState::initial(self) {
@@ -286,7 +215,8 @@ impl Maze {
             }
         }
 
-        queue.count_reverse_paths(self, best)
+        let spaces = queue.count_reverse_paths(self, best);
+        ((best != u32::MAX).then_some(best), spaces)
     }
 }
 
@@ -339,6 +269,16 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(|maze| maze.spaces_in_best_paths())
 }
 
+#[must_use]
+pub fn solve(input: &str) -> (Option<u32>, Option<u32>) {
+    let Ok(maze) = Maze::from_str(input) else {
+        return (None, None);
+    };
+
+    let (best, spaces) = maze.best_paths();
+    (best, Some(spaces))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -479,4 +419,16 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(45));
     }
+
+    #[test]
+    fn test_best_paths() {
+        let maze = example_maze();
+        assert_eq!(maze.best_paths(), (maze.best_path(), 45));
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(solve(&input), (part_one(&input), part_two(&input)));
+    }
 }
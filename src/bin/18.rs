@@ -1,28 +1,13 @@
 use std::collections::VecDeque;
 
-advent_of_code::solution!(18);
-
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+use advent_of_code::grid::Grid;
 
-const COMPASS: [Direction; 4] = [
-    Direction::North,
-    Direction::East,
-    Direction::South,
-    Direction::West,
-];
+advent_of_code::solution!(18);
 
 #[derive(Debug, PartialEq)]
-struct Grid {
-    height: usize,
-    width: usize,
+struct MemorySpace {
     corrupted: usize,
-    cells: Vec<usize>,
+    corrupted_at: Grid<usize>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -59,57 +44,19 @@ impl GridTravelStateQueue {
     }
 }
 
-impl Grid {
-    fn step(&self, position: usize, direction: Direction) -> Option<usize> {
-        let row = position / self.width;
-        let col = position % self.width;
-
-        let row = match direction {
-            Direction::North => row.checked_sub(1),
-            Direction::South => {
-                let south = row + 1;
-                if south >= self.height {
-                    None
-                } else {
-                    Some(south)
-                }
-            }
-            Direction::East | Direction::West => Some(row),
-        };
-        let row = row?;
-
-        let col = match direction {
-            Direction::West => col.checked_sub(1),
-            Direction::East => {
-                let east = col + 1;
-                if east >= self.width {
-                    None
-                } else {
-                    Some(east)
-                }
-            }
-            Direction::North | Direction::South => Some(col),
-        };
-        col.map(|col| (row * self.width) + col)
-    }
-
-    fn neighbours(&self, position: usize) -> impl Iterator<Item = usize> + use<'_> {
-        COMPASS
-            .into_iter()
-            .filter_map(move |direction| self.step(position, direction))
-    }
-
+impl MemorySpace {
     fn shortest_path_after(&self, nanoseconds: usize) -> Option<usize> {
-        let goal = (self.height * self.width) - 1;
-        let mut queue = GridTravelStateQueue::new(self.height, self.width);
+        let goal = (self.corrupted_at.height() * self.corrupted_at.width()) - 1;
+        let mut queue =
+            GridTravelStateQueue::new(self.corrupted_at.height(), self.corrupted_at.width());
 
         while let Some(state) = queue.pop() {
             if state.position == goal {
                 return Some(state.steps);
             }
 
-            for position in self.neighbours(state.position) {
-                if self.cells[position] > nanoseconds {
+            for position in self.corrupted_at.neighbours(state.position) {
+                if self.corrupted_at[position] > nanoseconds {
                     queue.push(GridTravelState {
                         position,
                         steps: state.steps + 1,
@@ -135,57 +82,57 @@ impl Grid {
             }
         }
 
-        self.cells
+        self.corrupted_at
             .iter()
             .position(|cell| *cell == upper)
             .map(|pos| {
-                let row = pos / self.width;
-                let col = pos % self.width;
+                let (row, col) = self.corrupted_at.coordinates(pos);
                 (col, row)
             })
     }
 }
 
 #[derive(Debug, PartialEq)]
-struct ParseGridError;
+struct ParseMemorySpaceError;
 
-impl Grid {
-    fn from_input(input: &str, height: usize, width: usize) -> Result<Self, ParseGridError> {
-        let mut cells = vec![usize::MAX; height * width];
+impl MemorySpace {
+    fn from_input(input: &str, height: usize, width: usize) -> Result<Self, ParseMemorySpaceError> {
+        let mut corrupted_at = Grid::new(width, height, usize::MAX);
         let mut corrupted = 0;
 
         for (nanosec, line) in (1..).zip(input.lines()) {
             let Some((x, y)) = line.split_once(',') else {
-                return Err(ParseGridError);
+                return Err(ParseMemorySpaceError);
             };
-            let x: usize = x.parse().map_err(|_| ParseGridError)?;
-            let y: usize = y.parse().map_err(|_| ParseGridError)?;
-            cells[(y * width) + x] = nanosec;
+            let x: usize = x.parse().map_err(|_| ParseMemorySpaceError)?;
+            let y: usize = y.parse().map_err(|_| ParseMemorySpaceError)?;
+            let cell = corrupted_at.get_mut(y, x).ok_or(ParseMemorySpaceError)?;
+            *cell = nanosec;
             corrupted = nanosec;
         }
 
         Ok(Self {
-            height,
-            width,
             corrupted,
-            cells,
+            corrupted_at,
         })
     }
 }
 
 #[must_use]
 pub fn part_one(input: &str) -> Option<usize> {
-    Grid::from_input(input, 71, 71)
+    MemorySpace::from_input(input, 71, 71)
         .ok()
         .and_then(|grid| grid.shortest_path_after(1024))
 }
 
 #[must_use]
 pub fn part_two(input: &str) -> Option<String> {
-    Grid::from_input(input, 71, 71).ok().and_then(|grid| {
-        grid.first_coordinate_blocking_exit()
-            .map(|coords| format!("{},{}", coords.0, coords.1))
-    })
+    MemorySpace::from_input(input, 71, 71)
+        .ok()
+        .and_then(|grid| {
+            grid.first_coordinate_blocking_exit()
+                .map(|coords| format!("{},{}", coords.0, coords.1))
+        })
 }
 
 #[cfg(test)]
@@ -196,53 +143,51 @@ mod tests {
         (y * 7) + x
     }
 
-    fn example_grid() -> Grid {
-        let mut cells = vec![usize::MAX; 7 * 7];
-        cells[position(5, 4)] = 1;
-        cells[position(4, 2)] = 2;
-        cells[position(4, 5)] = 3;
-        cells[position(3, 0)] = 4;
-        cells[position(2, 1)] = 5;
-        cells[position(6, 3)] = 6;
-        cells[position(2, 4)] = 7;
-        cells[position(1, 5)] = 8;
-        cells[position(0, 6)] = 9;
-        cells[position(3, 3)] = 10;
-        cells[position(2, 6)] = 11;
-        cells[position(5, 1)] = 12;
-        cells[position(1, 2)] = 13;
-        cells[position(5, 5)] = 14;
-        cells[position(2, 5)] = 15;
-        cells[position(6, 5)] = 16;
-        cells[position(1, 4)] = 17;
-        cells[position(0, 4)] = 18;
-        cells[position(6, 4)] = 19;
-        cells[position(1, 1)] = 20;
-        cells[position(6, 1)] = 21;
-        cells[position(1, 0)] = 22;
-        cells[position(0, 5)] = 23;
-        cells[position(1, 6)] = 24;
-        cells[position(2, 0)] = 25;
-
-        Grid {
-            height: 7,
-            width: 7,
+    fn example_memory_space() -> MemorySpace {
+        let mut corrupted_at = Grid::new(7, 7, usize::MAX);
+        corrupted_at[position(5, 4)] = 1;
+        corrupted_at[position(4, 2)] = 2;
+        corrupted_at[position(4, 5)] = 3;
+        corrupted_at[position(3, 0)] = 4;
+        corrupted_at[position(2, 1)] = 5;
+        corrupted_at[position(6, 3)] = 6;
+        corrupted_at[position(2, 4)] = 7;
+        corrupted_at[position(1, 5)] = 8;
+        corrupted_at[position(0, 6)] = 9;
+        corrupted_at[position(3, 3)] = 10;
+        corrupted_at[position(2, 6)] = 11;
+        corrupted_at[position(5, 1)] = 12;
+        corrupted_at[position(1, 2)] = 13;
+        corrupted_at[position(5, 5)] = 14;
+        corrupted_at[position(2, 5)] = 15;
+        corrupted_at[position(6, 5)] = 16;
+        corrupted_at[position(1, 4)] = 17;
+        corrupted_at[position(0, 4)] = 18;
+        corrupted_at[position(6, 4)] = 19;
+        corrupted_at[position(1, 1)] = 20;
+        corrupted_at[position(6, 1)] = 21;
+        corrupted_at[position(1, 0)] = 22;
+        corrupted_at[position(0, 5)] = 23;
+        corrupted_at[position(1, 6)] = 24;
+        corrupted_at[position(2, 0)] = 25;
+
+        MemorySpace {
             corrupted: 25,
-            cells,
+            corrupted_at,
         }
     }
 
     #[test]
     fn test_parse_input() {
         assert_eq!(
-            Grid::from_input(&advent_of_code::template::read_file("examples", DAY), 7, 7),
-            Ok(example_grid()),
+            MemorySpace::from_input(&advent_of_code::template::read_file("examples", DAY), 7, 7),
+            Ok(example_memory_space()),
         );
     }
 
     #[test]
     fn test_parse_neighbours() {
-        let grid = example_grid();
+        let grid = example_memory_space().corrupted_at;
         assert_eq!(
             grid.neighbours(position(0, 0)).collect::<Vec<usize>>(),
             vec![position(1, 0), position(0, 1)],
@@ -260,7 +205,7 @@ mod tests {
 
     #[test]
     fn test_shortest_path_after() {
-        assert_eq!(example_grid().shortest_path_after(12), Some(22))
+        assert_eq!(example_memory_space().shortest_path_after(12), Some(22))
     }
 
     #[test]
@@ -272,7 +217,7 @@ mod tests {
     #[test]
     fn test_first_coordinate_blocking_exit() {
         assert_eq!(
-            example_grid().first_coordinate_blocking_exit(),
+            example_memory_space().first_coordinate_blocking_exit(),
             Some((6, 1))
         );
     }
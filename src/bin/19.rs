@@ -4,6 +4,7 @@ use std::str::FromStr;
 advent_of_code::solution!(19);
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Onsen {
     towels: Vec<String>,
     patterns: Vec<String>,
@@ -142,6 +143,15 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let parsed =
+            Onsen::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<Onsen>(&json).unwrap(), parsed);
+    }
+
     #[test]
     fn test_is_pattern_possible() {
         let onsen = example_onsen();
@@ -1,6 +1,7 @@
-use std::collections::VecDeque;
 use std::str::FromStr;
 
+use advent_of_code::search::bfs_distances;
+
 advent_of_code::solution!(20);
 
 const GRID_SIZE: usize = 140;
@@ -10,21 +11,6 @@ const fn taxicab_distance(first: usize, second: usize) -> usize {
         + (first % GRID_SIZE).abs_diff(second % GRID_SIZE)
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-const COMPASS: [Direction; 4] = [
-    Direction::North,
-    Direction::East,
-    Direction::South,
-    Direction::West,
-];
-
 #[derive(Debug, PartialEq)]
 struct Maze {
     walls: Vec<bool>,
@@ -33,64 +19,8 @@ struct Maze {
 }
 
 impl Maze {
-    fn step_from(position: usize, direction: Direction) -> Option<usize> {
-        let row = position / GRID_SIZE;
-        let col = position % GRID_SIZE;
-
-        let row = match direction {
-            Direction::North => row.checked_sub(1),
-            Direction::South => {
-                let south = row + 1;
-                if south >= GRID_SIZE {
-                    None
-                } else {
-                    Some(south)
-                }
-            }
-            Direction::West | Direction::East => Some(row),
-        };
-        let row = row?;
-
-        let col = match direction {
-            Direction::West => col.checked_sub(1),
-            Direction::East => {
-                let east = col + 1;
-                if east >= GRID_SIZE {
-                    None
-                } else {
-                    Some(east)
-                }
-            }
-            Direction::North | Direction::South => Some(col),
-        };
-        col.map(|col| (row * GRID_SIZE) + col)
-    }
-
-    fn open_neighbours(&self, position: usize) -> impl Iterator<Item = usize> + use<'_> {
-        COMPASS.into_iter().filter_map(move |direction| {
-            Self::step_from(position, direction).and_then(|pos| {
-                if self.walls[pos] {
-                    None
-                } else {
-                    Some(pos)
-                }
-            })
-        })
-    }
-
     fn distances_from_start(&self) -> Vec<Option<usize>> {
-        let mut distance = vec![None; GRID_SIZE * GRID_SIZE];
-        let mut queue = VecDeque::new();
-        queue.push_back((self.end, 0));
-        while let Some((position, steps)) = queue.pop_front() {
-            if steps < distance[position].unwrap_or(usize::MAX) {
-                distance[position] = Some(steps);
-                for neighbour in self.open_neighbours(position) {
-                    queue.push_back((neighbour, steps + 1));
-                }
-            }
-        }
-        distance
+        bfs_distances(self.end, GRID_SIZE, GRID_SIZE, |pos| !self.walls[pos])
     }
 
     fn find_cheats(&self, max_cheat: usize, min_saving: usize) -> usize {
@@ -134,6 +134,18 @@ pub fn part_two(input: &str) -> Option<usize> {
         .and_then(|market| market.most_bananas_buyable())
 }
 
+#[must_use]
+pub fn solve(input: &str) -> (Option<usize>, Option<usize>) {
+    let Ok(market) = Market::from_str(input) else {
+        return (None, None);
+    };
+
+    (
+        Some(market.total_final_secret_numbers()),
+        market.most_bananas_buyable(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,4 +215,10 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(23));
     }
+
+    #[test]
+    fn test_solve() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(solve(&input), (part_one(&input), part_two(&input)));
+    }
 }
@@ -40,6 +40,15 @@ impl ComputerSet {
             .unwrap_or('!')
     }
 
+    fn computer_name(computer: usize) -> String {
+        [
+            Self::password_char(computer / 26),
+            Self::password_char(computer % 26),
+        ]
+        .iter()
+        .collect()
+    }
+
     fn password(&self) -> String {
         let mut password = String::new();
 
@@ -47,8 +56,7 @@ impl ComputerSet {
             if !password.is_empty() {
                 password.push(',');
             }
-            password.push(Self::password_char(computer / 26));
-            password.push(Self::password_char(computer % 26));
+            password.push_str(&Self::computer_name(computer));
         }
 
         password
@@ -140,6 +148,43 @@ impl Network {
     }
 }
 
+#[allow(dead_code)]
+impl Network {
+    fn largest_clique_size(&self) -> usize {
+        self.find_largest_group().map_or(0, |group| group.len())
+    }
+
+    fn node_count(&self) -> usize {
+        self.computers.len()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.computers.iter().flat_map(move |a| {
+            self.computers
+                .iter()
+                .filter(move |b| a < *b && self.connections.contains(a, *b))
+                .map(move |b| (a, b))
+        })
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+
+    fn to_edge_list(&self) -> String {
+        let mut edges = String::new();
+
+        for (a, b) in self.edges() {
+            edges.push_str(&ComputerSet::computer_name(a));
+            edges.push(' ');
+            edges.push_str(&ComputerSet::computer_name(b));
+            edges.push('\n');
+        }
+
+        edges
+    }
+}
+
 #[derive(Debug, PartialEq)]
 struct ParseNetworkError;
 
@@ -431,6 +476,30 @@ mod tests {
         assert_eq!(network.find_largest_group(), Some(expected));
     }
 
+    #[test]
+    fn test_node_and_edge_count() {
+        let network = example_network();
+        assert_eq!(network.node_count(), 16);
+        assert_eq!(network.edge_count(), 32);
+    }
+
+    #[test]
+    fn test_to_edge_list() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        let network = Network::from_str(&input).unwrap();
+        let edges = network.to_edge_list();
+
+        assert_eq!(edges.lines().count(), input.lines().count());
+        assert!(edges.lines().any(|line| line == "co de"));
+        assert!(edges.lines().all(|line| line.len() == 5));
+    }
+
+    #[test]
+    fn test_largest_clique_size() {
+        let network = example_network();
+        assert_eq!(network.largest_clique_size(), 4);
+    }
+
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
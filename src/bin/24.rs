@@ -1,8 +1,21 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
 use std::str::FromStr;
 
 advent_of_code::solution!(24);
 
-#[derive(Debug, PartialEq)]
+const X_WIRE: usize = 33;
+const Y_WIRE: usize = 34;
+const Z_WIRE: usize = 35;
+
+// wire indices are written as two decimal digits, e.g. the 3 in x03
+const WIRE_INDICES: usize = 100;
+
+const fn wire_id(prefix: usize, index: usize) -> usize {
+    (prefix * 36 * 36) + ((index / 10) * 36) + (index % 10)
+}
+
+#[derive(Clone, Debug, PartialEq)]
 enum Operation {
     And,
     Or,
@@ -17,77 +30,122 @@ impl Operation {
             Self::Xor => first ^ second,
         }
     }
+
+    const fn label(&self) -> &'static str {
+        match self {
+            Self::And => "AND",
+            Self::Or => "OR",
+            Self::Xor => "XOR",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 struct Gate {
     operation: Operation,
     inputs: [usize; 2],
     output: usize,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 struct System {
-    wires: Vec<Option<bool>>,
+    wires: HashMap<usize, bool>,
     gates: Vec<Gate>,
 }
 
 impl System {
-    fn calculate(mut self) -> usize {
-        loop {
-            let mut changed = false;
+    fn evaluate_round(&self, wires: &mut HashMap<usize, bool>) -> bool {
+        let mut changed = false;
 
-            for gate in &self.gates {
-                if self.wires[gate.output].is_some() {
-                    continue;
-                }
-                let Some(first) = self.wires[gate.inputs[0]] else {
-                    continue;
-                };
-                let Some(second) = self.wires[gate.inputs[1]] else {
-                    continue;
-                };
-                self.wires[gate.output] = Some(gate.operation.process(first, second));
-                changed = true;
+        for gate in &self.gates {
+            if wires.contains_key(&gate.output) {
+                continue;
             }
+            let Some(&first) = wires.get(&gate.inputs[0]) else {
+                continue;
+            };
+            let Some(&second) = wires.get(&gate.inputs[1]) else {
+                continue;
+            };
+            wires.insert(gate.output, gate.operation.process(first, second));
+            changed = true;
+        }
+
+        changed
+    }
 
-            if !changed {
+    fn evaluate(&self) -> HashMap<usize, bool> {
+        let mut wires = self.wires.clone();
+        while self.evaluate_round(&mut wires) {}
+        wires
+    }
+
+    fn calculate_bounded(mut self, max_rounds: usize) -> Option<usize> {
+        let mut wires = std::mem::take(&mut self.wires);
+
+        for _ in 0..max_rounds {
+            if !self.evaluate_round(&mut wires) {
                 break;
             }
         }
+        // gates stuck behind an undriven or cyclic input don't stop the network settling; only
+        // a round that would still change something past the cap means it hasn't
+        let settled = !self.evaluate_round(&mut wires);
+        self.wires = wires;
 
-        self.get_result()
+        settled.then(|| self.get_result())
+    }
+
+    fn calculate(self) -> Option<usize> {
+        // every round resolves at least one more gate, so this is always enough to settle
+        let max_rounds = self.gates.len() + 1;
+        self.calculate_bounded(max_rounds)
     }
 
     fn count_edges(&self, source: usize) -> usize {
-        let mut connected = vec![false; 36 * 36 * 36];
-        for gate in &self.gates {
-            if gate.inputs[0] == source || gate.inputs[1] == source {
-                connected[gate.output] = true;
-            }
-        }
-        connected.into_iter().filter(|x| *x).count()
+        self.gates
+            .iter()
+            .filter(|gate| gate.inputs[0] == source || gate.inputs[1] == source)
+            .map(|gate| gate.output)
+            .collect::<HashSet<usize>>()
+            .len()
     }
 
-    fn find_broken_nodes(&self) -> Vec<bool> {
+    fn input_bits(&self) -> usize {
+        (0..WIRE_INDICES)
+            .rev()
+            .find(|bit| self.wires.contains_key(&wire_id(X_WIRE, *bit)))
+            .map_or(0, |bit| bit + 1)
+    }
+
+    fn broken_with_reasons(&self) -> Vec<(usize, &'static str)> {
         // based on observing the output in graphviz, there are some common patterns which should
         // be present, and we can find the exceptions to this
-        let mut broken_nodes = vec![false; 36 * 36 * 36];
+        let mut broken_nodes = Vec::new();
+        let mut flag = |wire: usize, reason: &'static str| {
+            if !broken_nodes.contains(&(wire, reason)) {
+                broken_nodes.push((wire, reason));
+            }
+        };
+
+        let last_z = wire_id(Z_WIRE, self.input_bits());
+        let first_x = wire_id(X_WIRE, 0);
+        let first_y = wire_id(Y_WIRE, 0);
 
         for gate in &self.gates {
             // z nodes must not be inputs of other nodes
-            if gate.inputs[0] / (36 * 36) == 35 {
-                broken_nodes[gate.inputs[0]] = true;
+            if gate.inputs[0] / (36 * 36) == Z_WIRE {
+                flag(gate.inputs[0], "z-as-input");
             }
-            if gate.inputs[1] / (36 * 36) == 35 {
-                broken_nodes[gate.inputs[1]] = true;
+            if gate.inputs[1] / (36 * 36) == Z_WIRE {
+                flag(gate.inputs[1], "z-as-input");
             }
 
-            let output_is_z = gate.output / (36 * 36) == 35;
+            let output_is_z = gate.output / (36 * 36) == Z_WIRE;
 
-            // z nodes must be XOR, except for the last one, z45
-            if output_is_z && gate.output != 45509 && gate.operation != Operation::Xor {
-                broken_nodes[gate.output] = true;
+            // z nodes must be XOR, except for the last one (the final carry bit)
+            if output_is_z && gate.output != last_z && gate.operation != Operation::Xor {
+                flag(gate.output, "z-not-xor");
                 continue;
             }
 
@@ -96,9 +154,9 @@ impl System {
             let second = gate.inputs[1] / (36 * 36);
             if gate.operation == Operation::Xor
                 && !output_is_z
-                && !((first == 33 && second == 34) || (first == 34 && second == 33))
+                && !((first == X_WIRE && second == Y_WIRE) || (first == Y_WIRE && second == X_WIRE))
             {
-                broken_nodes[gate.output] = true;
+                flag(gate.output, "xor-wrong-inputs");
                 continue;
             }
 
@@ -106,7 +164,7 @@ impl System {
 
             // XOR nodes (except z nodes) should always be the input of exactly two other nodes
             if gate.operation == Operation::Xor && !output_is_z && edges != 2 {
-                broken_nodes[gate.output] = true;
+                flag(gate.output, "xor-wrong-fanout");
                 continue;
             }
 
@@ -114,38 +172,203 @@ impl System {
             // first one wired to x00 and y00
             if gate.operation == Operation::And
                 && !output_is_z
-                && !(gate.inputs == [42768, 44064] || gate.inputs == [44064, 42768])
+                && !(gate.inputs == [first_x, first_y] || gate.inputs == [first_y, first_x])
                 && edges != 1
             {
-                broken_nodes[gate.output] = true;
-                continue;
+                flag(gate.output, "and-wrong-fanout");
             }
         }
 
         broken_nodes
     }
 
-    fn get_result_digit(&self, digit: usize) -> usize {
-        let tens = digit / 10;
-        let ones = digit % 10;
-        let key = (35 * 36 * 36) + (tens * 36) + ones;
-        usize::from(self.wires[key].unwrap_or(false))
+    fn find_broken_nodes(&self) -> BTreeSet<usize> {
+        self.broken_with_reasons()
+            .into_iter()
+            .map(|(wire, _)| wire)
+            .collect()
     }
 
-    fn get_result(&self) -> usize {
+    fn get_digit(&self, prefix: usize, digit: usize) -> usize {
+        usize::from(
+            self.wires
+                .get(&wire_id(prefix, digit))
+                .copied()
+                .unwrap_or(false),
+        )
+    }
+
+    fn get_number(&self, prefix: usize) -> usize {
         (0..64)
-            .map(|x| self.get_result_digit(x) << x)
+            .map(|x| self.get_digit(prefix, x) << x)
             .fold(0, |a, b| a | b)
     }
+
+    fn get_result(&self) -> usize {
+        self.get_number(Z_WIRE)
+    }
+}
+
+#[allow(dead_code)]
+impl System {
+    fn verify_addition(&self) -> bool {
+        let expected = self.get_number(X_WIRE) + self.get_number(Y_WIRE);
+        self.clone().calculate() == Some(expected)
+    }
+
+    fn with_swaps(&self, swaps: &[(usize, usize)]) -> Self {
+        let mut system = self.clone();
+
+        for gate in &mut system.gates {
+            for (first, second) in swaps {
+                if gate.output == *first {
+                    gate.output = *second;
+                    break;
+                } else if gate.output == *second {
+                    gate.output = *first;
+                    break;
+                }
+            }
+        }
+
+        system
+    }
+
+    fn verify_swaps(&self, swaps: &[(usize, usize)]) -> bool {
+        self.with_swaps(swaps).verify_addition()
+    }
+
+    fn add(&self, x: u64, y: u64) -> Option<u64> {
+        if self.has_cycle() {
+            return None;
+        }
+
+        let bits = u32::try_from(self.input_bits()).ok()?;
+        // operands wider than the x and y wires can't be represented, so refuse them
+        if x.checked_shr(bits).unwrap_or(0) != 0 || y.checked_shr(bits).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        let mut system = self.clone();
+
+        for bit in 0..self.input_bits() {
+            system
+                .wires
+                .insert(wire_id(X_WIRE, bit), (x >> bit) & 1 == 1);
+            system
+                .wires
+                .insert(wire_id(Y_WIRE, bit), (y >> bit) & 1 == 1);
+        }
+
+        system.calculate().map(|z| z as u64)
+    }
+
+    fn to_dot(&self) -> String {
+        let mut wires: Vec<usize> = self
+            .gates
+            .iter()
+            .flat_map(|gate| [gate.inputs[0], gate.inputs[1], gate.output])
+            .collect();
+        wires.sort_unstable();
+        wires.dedup();
+
+        let mut dot = String::from("digraph {\n");
+
+        for wire in wires {
+            let name = wire_name(wire);
+            let _ = writeln!(dot, "    \"{name}\" [label=\"{name}\"];");
+        }
+
+        for (ix, gate) in self.gates.iter().enumerate() {
+            let label = gate.operation.label();
+            let _ = writeln!(dot, "    gate{ix} [label=\"{label}\", shape=box];");
+
+            for input in gate.inputs {
+                let _ = writeln!(dot, "    \"{}\" -> gate{ix};", wire_name(input));
+            }
+
+            let _ = writeln!(dot, "    gate{ix} -> \"{}\";", wire_name(gate.output));
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    fn wire_value(&self, name: &str) -> Option<bool> {
+        let wire = parse_wire(name).ok()?;
+        self.evaluate().get(&wire).copied()
+    }
+
+    fn has_cycle(&self) -> bool {
+        let mut unresolved: HashSet<usize> = self
+            .gates
+            .iter()
+            .flat_map(|gate| [gate.inputs[0], gate.inputs[1], gate.output])
+            .filter(|wire| !self.wires.contains_key(wire))
+            .collect();
+
+        let mut pending: Vec<&Gate> = self.gates.iter().collect();
+        loop {
+            let before = pending.len();
+            pending.retain(|gate| {
+                if !unresolved.contains(&gate.inputs[0]) && !unresolved.contains(&gate.inputs[1]) {
+                    unresolved.remove(&gate.output);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            if pending.is_empty() {
+                return false;
+            }
+            if pending.len() == before {
+                return true;
+            }
+        }
+    }
+
+    fn input_operands(&self) -> (u64, u64) {
+        (
+            self.get_number(X_WIRE) as u64,
+            self.get_number(Y_WIRE) as u64,
+        )
+    }
+
+    fn cone_size(&self, output_wire: usize) -> usize {
+        let sources: HashMap<usize, &Gate> =
+            self.gates.iter().map(|gate| (gate.output, gate)).collect();
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![output_wire];
+
+        while let Some(wire) = stack.pop() {
+            let Some(gate) = sources.get(&wire) else {
+                continue;
+            };
+            if visited.insert(wire) {
+                stack.extend(gate.inputs);
+            }
+        }
+
+        visited.len()
+    }
 }
 
 #[derive(Debug, PartialEq)]
 struct ParseSystemError;
 
 fn parse_wire(wire: &str) -> Result<usize, ParseSystemError> {
+    if wire.chars().count() != 3 {
+        return Err(ParseSystemError);
+    }
+
     let mut value = 0;
 
     for ch in wire.chars() {
+        if !(ch.is_ascii_lowercase() || ch.is_ascii_digit()) {
+            return Err(ParseSystemError);
+        }
         let digit = ch.to_digit(36).ok_or(ParseSystemError)?;
         value = (value * 36) + digit;
     }
@@ -193,7 +416,7 @@ impl FromStr for System {
     type Err = ParseSystemError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut wires = vec![None; 36 * 36 * 36];
+        let mut wires = HashMap::new();
         let mut gates = Vec::new();
 
         let (wires_str, gates_str) = input.split_once("\n\n").ok_or(ParseSystemError)?;
@@ -206,7 +429,7 @@ impl FromStr for System {
                 Some('0') => false,
                 _ => return Err(ParseSystemError),
             };
-            wires[wire] = Some(value);
+            wires.insert(wire, value);
         }
 
         for line in gates_str.lines() {
@@ -240,25 +463,14 @@ fn wire_name(wire: usize) -> String {
 
 #[must_use]
 pub fn part_one(input: &str) -> Option<usize> {
-    System::from_str(input).ok().map(System::calculate)
+    System::from_str(input).ok().and_then(System::calculate)
 }
 
 #[must_use]
 pub fn part_two(input: &str) -> Option<String> {
     System::from_str(input).ok().map(|system| {
         let mut output = String::new();
-        for name in system
-            .find_broken_nodes()
-            .into_iter()
-            .enumerate()
-            .filter_map(|(gate, is_broken)| {
-                if is_broken {
-                    Some(wire_name(gate))
-                } else {
-                    None
-                }
-            })
-        {
+        for name in system.find_broken_nodes().into_iter().map(wire_name) {
             if !output.is_empty() {
                 output.push(',');
             }
@@ -271,20 +483,47 @@ pub fn part_two(input: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::solution::Solution;
+
+    fn cyclic_system() -> System {
+        let mut wires = HashMap::new();
+        wires.insert(42768, true);
+        wires.insert(44064, false);
+        System {
+            wires,
+            gates: vec![
+                Gate {
+                    operation: Operation::And,
+                    inputs: [42768, 13330],
+                    output: 14663,
+                },
+                Gate {
+                    operation: Operation::Or,
+                    inputs: [14663, 44064],
+                    output: 13330,
+                },
+                Gate {
+                    operation: Operation::Xor,
+                    inputs: [42768, 44064],
+                    output: 45360,
+                },
+            ],
+        }
+    }
 
     #[test]
     fn test_parse_system() {
-        let mut wires = vec![None; 36 * 36 * 36];
-        wires[42768] = Some(true);
-        wires[42769] = Some(false);
-        wires[42770] = Some(true);
-        wires[42771] = Some(true);
-        wires[42772] = Some(false);
-        wires[44064] = Some(true);
-        wires[44065] = Some(true);
-        wires[44066] = Some(true);
-        wires[44067] = Some(true);
-        wires[44068] = Some(true);
+        let mut wires = HashMap::new();
+        wires.insert(42768, true);
+        wires.insert(42769, false);
+        wires.insert(42770, true);
+        wires.insert(42771, true);
+        wires.insert(42772, false);
+        wires.insert(44064, true);
+        wires.insert(44065, true);
+        wires.insert(44066, true);
+        wires.insert(44067, true);
+        wires.insert(44068, true);
 
         let system = System {
             wires,
@@ -482,4 +721,205 @@ mod tests {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(2024));
     }
+
+    #[test]
+    fn test_input_bits() {
+        let system = System::from_str(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(system.map(|s| s.input_bits()), Ok(5));
+
+        let adder = System::from_str(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ));
+        assert_eq!(adder.map(|s| s.input_bits()), Ok(3));
+
+        let mut gapped = cyclic_system();
+        gapped.wires.insert(wire_id(X_WIRE, 2), true);
+        assert_eq!(gapped.input_bits(), 3);
+    }
+
+    #[test]
+    fn test_verify_addition() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert!(!system.find_broken_nodes().is_empty());
+        assert!(!system.verify_addition());
+
+        let adder = System::from_str(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ))
+        .unwrap();
+        assert!(!adder.verify_addition());
+        assert!(adder.verify_swaps(&[(parse_wire("b01").unwrap(), parse_wire("z01").unwrap())]));
+        assert!(!adder.verify_swaps(&[(parse_wire("z00").unwrap(), parse_wire("c00").unwrap())]));
+    }
+
+    #[test]
+    fn test_add() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert_eq!(system.add(13, 31), Some(2024));
+        assert_eq!(system.add(32, 0), None);
+
+        let adder = System::from_str(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ))
+        .unwrap()
+        .with_swaps(&[(parse_wire("b01").unwrap(), parse_wire("z01").unwrap())]);
+        for x in 0..8 {
+            for y in 0..8 {
+                assert_eq!(adder.add(x, y), Some(x + y));
+            }
+        }
+        assert_eq!(adder.add(8, 1), None);
+        assert_eq!(adder.add(1, 8), None);
+
+        assert_eq!(cyclic_system().add(1, 0), None);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let dot = system.to_dot();
+
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("    \"x00\" [label=\"x00\"];"));
+        assert!(dot.contains("    \"mjb\" [label=\"mjb\"];"));
+        assert!(dot.contains("    gate0 [label=\"XOR\", shape=box];"));
+        assert!(dot.contains("    gate1 [label=\"OR\", shape=box];"));
+        assert!(dot.contains("    gate6 [label=\"AND\", shape=box];"));
+        assert!(dot.contains("    \"ntg\" -> gate0;"));
+        assert!(dot.contains("    \"fgs\" -> gate0;"));
+        assert!(dot.contains("    gate0 -> \"mjb\";"));
+        assert!(dot.ends_with('}'));
+    }
+
+    #[test]
+    fn test_wire_value() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert_eq!(system.wire_value("x01"), Some(false));
+        assert_eq!(system.wire_value("mjb"), Some(true));
+        assert_eq!(system.wire_value("djm"), Some(true));
+        assert_eq!(system.wire_value("ntg"), Some(false));
+        assert_eq!(system.wire_value("z00"), Some(false));
+        assert_eq!(system.wire_value("abc"), None);
+        assert_eq!(system.wire_value("not a wire"), None);
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert!(!system.has_cycle());
+
+        let cyclic = cyclic_system();
+        assert!(cyclic.has_cycle());
+
+        let undriven = System::from_str("x00: 1\n\nabc AND x00 -> z00").unwrap();
+        assert!(undriven.has_cycle());
+    }
+
+    #[test]
+    fn test_input_operands() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert_eq!(system.input_operands(), (13, 31));
+
+        let adder = System::from_str(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ))
+        .unwrap();
+        assert_eq!(adder.input_operands(), (3, 1));
+    }
+
+    #[test]
+    fn test_wire_name_round_trip() {
+        for wire in 0..(36 * 36 * 36) {
+            assert_eq!(parse_wire(&wire_name(wire)), Ok(wire));
+        }
+        assert_eq!(wire_name(parse_wire("z45").unwrap()), "z45");
+        assert_eq!(parse_wire("z4"), Err(ParseSystemError));
+        assert_eq!(parse_wire("z450"), Err(ParseSystemError));
+        assert_eq!(parse_wire("Z00"), Err(ParseSystemError));
+        assert_eq!(parse_wire("z-1"), Err(ParseSystemError));
+    }
+
+    #[test]
+    fn test_broken_with_reasons() {
+        let adder = System::from_str(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ))
+        .unwrap();
+        assert_eq!(
+            adder.broken_with_reasons(),
+            vec![
+                (parse_wire("b01").unwrap(), "xor-wrong-inputs"),
+                (parse_wire("z01").unwrap(), "z-not-xor"),
+            ],
+        );
+
+        let fixed = adder.with_swaps(&[(parse_wire("b01").unwrap(), parse_wire("z01").unwrap())]);
+        assert_eq!(fixed.broken_with_reasons(), Vec::new());
+    }
+
+    #[test]
+    fn test_calculate_bounded() {
+        // the example settles in exactly three rounds, so this exercises the round cap itself
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert_eq!(system.clone().calculate_bounded(1), None);
+        assert_eq!(system.clone().calculate_bounded(2), None);
+        assert_eq!(system.clone().calculate_bounded(3), Some(2024));
+        assert_eq!(system.calculate_bounded(100), Some(2024));
+
+        // gates stuck in a cycle stop changing, so the rest of the network still settles
+        let cyclic = cyclic_system();
+        assert_eq!(cyclic.calculate_bounded(1000), Some(1));
+    }
+
+    #[test]
+    fn test_calculate_with_undriven_gate() {
+        let mut system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        system.gates.push(Gate {
+            operation: Operation::And,
+            inputs: [parse_wire("abc").unwrap(), parse_wire("x00").unwrap()],
+            output: parse_wire("qqq").unwrap(),
+        });
+        assert!(system.has_cycle());
+        assert_eq!(system.calculate(), Some(2024));
+    }
+
+    #[test]
+    fn test_cone_size() {
+        let system =
+            System::from_str(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert_eq!(system.cone_size(parse_wire("z00").unwrap()), 7);
+        assert_eq!(system.cone_size(parse_wire("z03").unwrap()), 6);
+        assert_eq!(system.cone_size(parse_wire("x00").unwrap()), 0);
+
+        let adder = System::from_str(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ))
+        .unwrap();
+        assert_eq!(adder.cone_size(parse_wire("z00").unwrap()), 1);
+        assert_eq!(adder.cone_size(parse_wire("z01").unwrap()), 3);
+        assert_eq!(adder.cone_size(parse_wire("z02").unwrap()), 7);
+        assert_eq!(adder.cone_size(parse_wire("z03").unwrap()), 9);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ));
+        assert_eq!(result, Some("b01,z01".to_string()));
+    }
+
+    #[test]
+    fn test_solution_trait() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(Solver::part_one(&input), Some("2024".to_string()));
+    }
 }
@@ -2,29 +2,106 @@ use std::str::FromStr;
 
 advent_of_code::solution!(25);
 
-type Lock = [u8; 5];
+type Lock = Vec<u8>;
 
-fn key_fits_lock(key: Lock, lock: Lock) -> bool {
-    (0..5).all(|c| lock[c] + key[c] <= 7)
+fn key_fits_lock(key: &Lock, lock: &Lock, height: u8) -> bool {
+    key.len() == lock.len()
+        && lock
+            .iter()
+            .zip(key.iter())
+            .all(|(l, k)| u16::from(*l) + u16::from(*k) <= u16::from(height))
 }
 
 #[derive(Debug, PartialEq)]
 struct Door {
     locks: Vec<Lock>,
     keys: Vec<Lock>,
+    height: u8,
 }
 
 impl Door {
     fn non_overlapping_combos(&self) -> usize {
         self.locks
             .iter()
-            .flat_map(|lock| self.keys.iter().filter(|key| key_fits_lock(**key, *lock)))
+            .flat_map(|lock| {
+                self.keys
+                    .iter()
+                    .filter(|key| key_fits_lock(key, lock, self.height))
+            })
             .count()
     }
 }
 
+#[allow(dead_code)]
+impl Door {
+    fn fitting_pairs(&self) -> Vec<(usize, usize)> {
+        self.locks
+            .iter()
+            .enumerate()
+            .flat_map(|(lock_ix, lock)| {
+                self.keys
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, key)| key_fits_lock(key, lock, self.height))
+                    .map(move |(key_ix, _)| (lock_ix, key_ix))
+            })
+            .collect()
+    }
+
+    fn render(&self) -> String {
+        [("lock", &self.locks), ("key", &self.keys)]
+            .into_iter()
+            .flat_map(|(kind, schematics)| {
+                schematics.iter().enumerate().map(move |(ix, heights)| {
+                    let heights: Vec<String> = heights.iter().map(u8::to_string).collect();
+                    format!("{kind:<4} {ix:>2}: [{}]\n", heights.join(","))
+                })
+            })
+            .collect()
+    }
+
+    const fn lock_count(&self) -> usize {
+        self.locks.len()
+    }
+
+    const fn key_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn tightest_key_for(&self, lock_index: usize) -> Option<usize> {
+        let lock = self.locks.get(lock_index)?;
+
+        self.keys
+            .iter()
+            .enumerate()
+            .filter(|(_, key)| key_fits_lock(key, lock, self.height))
+            .min_by_key(|(_, key)| {
+                lock.iter()
+                    .zip(key.iter())
+                    .map(|(l, k)| u32::from(self.height) - (u32::from(*l) + u32::from(*k)))
+                    .sum::<u32>()
+            })
+            .map(|(key_ix, _)| key_ix)
+    }
+}
+
 #[derive(Debug, PartialEq)]
-struct ParseDoorError;
+enum ParseDoorError {
+    EmptySchematic,
+    UnknownSchematic,
+    InconsistentHeight,
+    TooTall,
+    InconsistentWidth { expected: usize, found: usize },
+}
+
+fn row_fill(row: &str) -> Option<char> {
+    let first = row.chars().next()?;
+    if (first == '#' || first == '.') && row.chars().all(|ch| ch == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
 
 impl FromStr for Door {
     type Err = ParseDoorError;
@@ -32,16 +109,34 @@ impl FromStr for Door {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut locks = Vec::new();
         let mut keys = Vec::new();
+        let mut height = None;
+        let mut width = None;
 
         for part in input.split("\n\n") {
-            let mut lines = part.lines().peekable();
-            let mut heights: Lock = [0; 5];
+            let lines: Vec<&str> = part.lines().collect();
 
-            let is_key = if let Some(first) = lines.peek() {
-                first == &"....."
-            } else {
-                return Err(ParseDoorError);
+            let (Some(top), Some(bottom)) = (lines.first(), lines.last()) else {
+                return Err(ParseDoorError::EmptySchematic);
             };
+            let is_key = match (row_fill(top), row_fill(bottom)) {
+                (Some('.'), Some('#')) => true,
+                (Some('#'), Some('.')) => false,
+                _ => return Err(ParseDoorError::UnknownSchematic),
+            };
+
+            let rows = u8::try_from(lines.len()).map_err(|_| ParseDoorError::TooTall)?;
+            if *height.get_or_insert(rows) != rows {
+                return Err(ParseDoorError::InconsistentHeight);
+            }
+
+            let cols = *width.get_or_insert(top.len());
+            if let Some(line) = lines.iter().find(|line| line.len() != cols) {
+                return Err(ParseDoorError::InconsistentWidth {
+                    expected: cols,
+                    found: line.len(),
+                });
+            }
+            let mut heights: Lock = vec![0; cols];
 
             for line in lines {
                 for (col, ch) in line.chars().enumerate() {
@@ -58,7 +153,11 @@ impl FromStr for Door {
             }
         }
 
-        Ok(Self { locks, keys })
+        Ok(Self {
+            locks,
+            keys,
+            height: height.unwrap_or(0),
+        })
     }
 }
 
@@ -81,8 +180,13 @@ mod tests {
 
     fn example_door() -> Door {
         Door {
-            locks: vec![[1, 6, 4, 5, 4], [2, 3, 1, 6, 4]],
-            keys: vec![[6, 1, 3, 2, 4], [5, 4, 5, 1, 3], [4, 1, 3, 1, 2]],
+            locks: vec![vec![1, 6, 4, 5, 4], vec![2, 3, 1, 6, 4]],
+            keys: vec![
+                vec![6, 1, 3, 2, 4],
+                vec![5, 4, 5, 1, 3],
+                vec![4, 1, 3, 1, 2],
+            ],
+            height: 7,
         }
     }
 
@@ -94,6 +198,173 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_door_six_pins() {
+        let input = "######\n.#####\n..#.##\n......\n......\n......\n......\n\n\
+                     ......\n......\n......\n#....#\n##...#\n###..#\n######\n\n\
+                     ......\n..#...\n..#...\n..#...\n..#...\n..#...\n######";
+        let door = Door::from_str(input).unwrap();
+        assert_eq!(door.locks, vec![vec![1, 2, 3, 2, 3, 3]]);
+        assert_eq!(
+            door.keys,
+            vec![vec![4, 3, 2, 1, 1, 4], vec![1, 1, 6, 1, 1, 1]]
+        );
+        assert_eq!(door.non_overlapping_combos(), 1);
+    }
+
+    #[test]
+    fn test_parse_door_short_schematics() {
+        let input = "#####\n.#.##\n.....\n.....\n.....\n\n\
+                     .....\n.....\n#....\n##...\n#####\n\n\
+                     .....\n.#...\n.#...\n.#...\n#####";
+        let door = Door::from_str(input).unwrap();
+        assert_eq!(door.height, 5);
+        assert_eq!(door.locks, vec![vec![1, 2, 1, 2, 2]]);
+        assert_eq!(door.keys, vec![vec![3, 2, 1, 1, 1], vec![1, 4, 1, 1, 1]]);
+        assert_eq!(door.fitting_pairs(), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn test_parse_door_mixed_heights() {
+        let input = "#####\n.#.##\n.....\n.....\n.....\n\n\
+                     .....\n.....\n#....\n##...\n#...#\n#####";
+        assert_eq!(
+            Door::from_str(input),
+            Err(ParseDoorError::InconsistentHeight)
+        );
+    }
+
+    #[test]
+    fn test_parse_door_too_tall() {
+        let rows: Vec<&str> = (0..256)
+            .map(|row| if row == 0 { "#####" } else { "....." })
+            .collect();
+        let lock = rows.join("\n");
+        assert_eq!(
+            Door::from_str(&format!("{lock}\n\n{lock}")),
+            Err(ParseDoorError::TooTall)
+        );
+    }
+
+    #[test]
+    fn test_parse_door_mixed_widths() {
+        let input = "#####\n.#.##\n.....\n.....\n.....\n\n\
+                     ......\n......\n#.....\n##....\n######";
+        assert_eq!(
+            Door::from_str(input),
+            Err(ParseDoorError::InconsistentWidth {
+                expected: 5,
+                found: 6
+            }),
+        );
+    }
+
+    #[test]
+    fn test_parse_door_malformed_edges() {
+        let stray_pin = "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.#...";
+        assert_eq!(
+            Door::from_str(stray_pin),
+            Err(ParseDoorError::UnknownSchematic)
+        );
+
+        let stray_space = ".... \n#....\n#....\n#...#\n#.#.#\n#.###\n#####";
+        assert_eq!(
+            Door::from_str(stray_space),
+            Err(ParseDoorError::UnknownSchematic)
+        );
+
+        let both_solid = "#####\n#....\n#....\n#...#\n#.#.#\n#.###\n#####";
+        assert_eq!(
+            Door::from_str(both_solid),
+            Err(ParseDoorError::UnknownSchematic)
+        );
+    }
+
+    #[test]
+    fn test_tall_schematics() {
+        let schematic = |filled: usize, is_key: bool| {
+            let rows: Vec<&str> = (0..200)
+                .map(|row| {
+                    let from_edge = if is_key { 199 - row } else { row };
+                    if from_edge < filled {
+                        "#####"
+                    } else {
+                        "....."
+                    }
+                })
+                .collect();
+            rows.join("\n")
+        };
+        let input = [
+            schematic(150, false),
+            schematic(150, true),
+            schematic(50, true),
+        ]
+        .join("\n\n");
+
+        let door = Door::from_str(&input).unwrap();
+        assert_eq!(door.height, 200);
+        assert_eq!(door.fitting_pairs(), vec![(0, 1)]);
+        assert_eq!(door.tightest_key_for(0), Some(1));
+    }
+
+    #[test]
+    fn test_lock_and_key_count() {
+        let door = example_door();
+        assert_eq!(door.lock_count(), 2);
+        assert_eq!(door.key_count(), 3);
+    }
+
+    #[test]
+    fn test_parse_door_ragged_rows() {
+        let long_row = "#####\n.####\n.#####\n.####\n.#.#.\n.#...\n.....";
+        assert_eq!(
+            Door::from_str(long_row),
+            Err(ParseDoorError::InconsistentWidth {
+                expected: 5,
+                found: 6
+            }),
+        );
+
+        let short_row = ".....\n#....\n#....\n#..\n#.#.#\n#.###\n#####";
+        assert_eq!(
+            Door::from_str(short_row),
+            Err(ParseDoorError::InconsistentWidth {
+                expected: 5,
+                found: 3
+            }),
+        );
+    }
+
+    #[test]
+    fn test_fitting_pairs() {
+        let door = example_door();
+        let pairs = door.fitting_pairs();
+        assert_eq!(pairs, vec![(0, 2), (1, 1), (1, 2)]);
+        assert_eq!(pairs.len(), door.non_overlapping_combos());
+    }
+
+    #[test]
+    fn test_tightest_key_for() {
+        let door = example_door();
+        assert_eq!(door.tightest_key_for(0), Some(2));
+        assert_eq!(door.tightest_key_for(1), Some(1));
+        assert_eq!(door.tightest_key_for(2), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = example_door().render();
+        assert_eq!(
+            rendered,
+            "lock  0: [1,6,4,5,4]\n\
+             lock  1: [2,3,1,6,4]\n\
+             key   0: [6,1,3,2,4]\n\
+             key   1: [5,4,5,1,3]\n\
+             key   2: [4,1,3,1,2]\n",
+        );
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
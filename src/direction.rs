@@ -0,0 +1,117 @@
+/// One of the four orthogonal directions on a grid, where north is towards row zero.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// All four directions, in clockwise order starting from north.
+pub const COMPASS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
+
+impl Direction {
+    /// Returns the direction pointing the opposite way.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Returns the direction after a quarter turn anticlockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// Returns the direction after a quarter turn clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// Steps one cell in this direction from `position` in a row-major grid of the given size,
+    /// returning [`None`] if that would leave the grid.
+    #[must_use]
+    pub const fn step(self, position: usize, width: usize, height: usize) -> Option<usize> {
+        let row = position / width;
+        let col = position % width;
+
+        match self {
+            Self::North if row > 0 => Some(position - width),
+            Self::South if row + 1 < height => Some(position + width),
+            Self::West if col > 0 => Some(position - 1),
+            Self::East if col + 1 < width => Some(position + 1),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the positions orthogonally adjacent to `position` in a row-major grid of the given
+/// size, in clockwise order starting from north, skipping any which would be outside the grid.
+pub fn orthogonal_neighbours(
+    position: usize,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = usize> {
+    COMPASS
+        .into_iter()
+        .filter_map(move |direction| direction.step(position, width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in COMPASS {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.opposite().opposite(), direction);
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn test_step() {
+        // a grid 4 wide and 3 tall, starting in the middle of the second row
+        assert_eq!(Direction::North.step(5, 4, 3), Some(1));
+        assert_eq!(Direction::East.step(5, 4, 3), Some(6));
+        assert_eq!(Direction::South.step(5, 4, 3), Some(9));
+        assert_eq!(Direction::West.step(5, 4, 3), Some(4));
+
+        assert_eq!(Direction::North.step(2, 4, 3), None);
+        assert_eq!(Direction::East.step(7, 4, 3), None);
+        assert_eq!(Direction::South.step(10, 4, 3), None);
+        assert_eq!(Direction::West.step(8, 4, 3), None);
+    }
+
+    #[test]
+    fn test_orthogonal_neighbours() {
+        let neighbours = |position| orthogonal_neighbours(position, 4, 3).collect::<Vec<usize>>();
+        assert_eq!(neighbours(5), vec![1, 6, 9, 4]);
+        assert_eq!(neighbours(0), vec![1, 4]);
+        assert_eq!(neighbours(11), vec![7, 10]);
+    }
+}
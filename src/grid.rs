@@ -0,0 +1,158 @@
+use std::ops::{Index, IndexMut};
+
+use crate::direction::orthogonal_neighbours;
+
+/// A rectangular grid of cells stored row-major in a flat vector, so that each cell can be
+/// identified by a single `usize` position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `fill`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from lines of text, converting each character with `map`. Returns [`None`]
+    /// if any character can't be mapped or the lines aren't all the same length.
+    pub fn from_chars(input: &str, mut map: impl FnMut(char) -> Option<T>) -> Option<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let before = cells.len();
+            for ch in line.chars() {
+                cells.push(map(ch)?);
+            }
+            if *width.get_or_insert(cells.len() - before) != cells.len() - before {
+                return None;
+            }
+            height += 1;
+        }
+
+        Some(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the position of the cell at `row` and `col`, if it is within the grid.
+    #[must_use]
+    pub const fn position(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.height && col < self.width {
+            Some((row * self.width) + col)
+        } else {
+            None
+        }
+    }
+
+    /// Splits a position back into its `(row, col)` coordinates.
+    #[must_use]
+    pub const fn coordinates(&self, position: usize) -> (usize, usize) {
+        (position / self.width, position % self.width)
+    }
+
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.position(row, col).map(|pos| &self.cells[pos])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.position(row, col).map(|pos| &mut self.cells[pos])
+    }
+
+    /// Returns the positions orthogonally adjacent to `position` which are within the grid, in
+    /// clockwise order starting from north.
+    pub fn neighbours(&self, position: usize) -> impl Iterator<Item = usize> + use<'_, T> {
+        orthogonal_neighbours(position, self.width, self.height)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: usize) -> &Self::Output {
+        &self.cells[position]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, position: usize) -> &mut Self::Output {
+        &mut self.cells[position]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_grid() -> Grid<bool> {
+        Grid::from_chars("#..\n.#.", |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_chars() {
+        let grid = example_grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 0), Some(&true));
+        assert_eq!(grid.get(1, 1), Some(&true));
+        assert_eq!(grid.get(1, 2), Some(&false));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert!(grid[4]);
+    }
+
+    #[test]
+    fn test_from_chars_invalid() {
+        assert_eq!(Grid::from_chars("ab\nc", Some), None);
+        assert_eq!(Grid::from_chars("ab\ncd", |_| None::<char>), None);
+    }
+
+    #[test]
+    fn test_positions() {
+        let grid = example_grid();
+        assert_eq!(grid.position(1, 2), Some(5));
+        assert_eq!(grid.position(2, 2), None);
+        assert_eq!(grid.coordinates(5), (1, 2));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example_grid();
+        assert_eq!(grid.neighbours(0).collect::<Vec<usize>>(), vec![1, 3]);
+        assert_eq!(grid.neighbours(4).collect::<Vec<usize>>(), vec![1, 5, 3]);
+    }
+}
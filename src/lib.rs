@@ -1,3 +1,8 @@
+pub mod direction;
+pub mod grid;
+pub mod parse;
+pub mod search;
+pub mod solution;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
@@ -0,0 +1,20 @@
+/// Converts a character from `'0'` to `'9'` into its value as a decimal digit.
+#[must_use]
+pub fn parse_decimal_digit(ch: char) -> Option<u8> {
+    ch.to_digit(10).and_then(|digit| u8::try_from(digit).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal_digit() {
+        assert_eq!(parse_decimal_digit('0'), Some(0));
+        assert_eq!(parse_decimal_digit('7'), Some(7));
+        assert_eq!(parse_decimal_digit('9'), Some(9));
+        assert_eq!(parse_decimal_digit('a'), None);
+        assert_eq!(parse_decimal_digit('.'), None);
+        assert_eq!(parse_decimal_digit('٣'), None);
+    }
+}
@@ -0,0 +1,125 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, VecDeque};
+
+use crate::direction::orthogonal_neighbours;
+
+/// Finds the number of orthogonal steps from `start` to every position in a row-major grid.
+///
+/// Only positions for which `passable` returns `true` can be moved through. Positions which
+/// can't be reached have a distance of [`None`].
+pub fn bfs_distances(
+    start: usize,
+    width: usize,
+    height: usize,
+    passable: impl Fn(usize) -> bool,
+) -> Vec<Option<usize>> {
+    let mut distance = vec![None; width * height];
+    let mut queue = VecDeque::new();
+
+    distance[start] = Some(0);
+    queue.push_back((start, 0));
+
+    while let Some((position, steps)) = queue.pop_front() {
+        for neighbour in orthogonal_neighbours(position, width, height) {
+            if distance[neighbour].is_none() && passable(neighbour) {
+                distance[neighbour] = Some(steps + 1);
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+
+    distance
+}
+
+/// Finds the lowest total cost of reaching a goal state from any of the `start_states`.
+///
+/// Each start state comes with its initial cost, and `successors` gives the states reachable
+/// from a state along with the extra cost of moving to each of them. Returns [`None`] if no state
+/// satisfying `is_goal` can be reached.
+pub fn dijkstra<S, I>(
+    start_states: impl IntoIterator<Item = (S, u32)>,
+    successors: impl Fn(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<u32>
+where
+    S: Clone + Ord,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let mut best = BTreeMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for (state, cost) in start_states {
+        if best.get(&state).is_none_or(|known| cost < *known) {
+            best.insert(state.clone(), cost);
+            queue.push(Reverse((cost, state)));
+        }
+    }
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if best.get(&state).is_some_and(|known| cost > *known) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(cost);
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if best.get(&next).is_none_or(|known| next_cost < *known) {
+                best.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs_distances() {
+        // .#.
+        // ...
+        // #..
+        let walls = [1, 6];
+        let distances = bfs_distances(0, 3, 3, |pos| !walls.contains(&pos));
+
+        assert_eq!(
+            distances,
+            vec![
+                Some(0),
+                None,
+                Some(4),
+                Some(1),
+                Some(2),
+                Some(3),
+                None,
+                Some(3),
+                Some(4),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // 0 -> 1 (7), 0 -> 2 (2), 2 -> 1 (3), 1 -> 3 (1), 2 -> 3 (9)
+        let edges = [(0, 1, 7), (0, 2, 2), (2, 1, 3), (1, 3, 1), (2, 3, 9)];
+        let successors = |state: &u32| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == state)
+                .map(|(_, to, cost)| (*to, *cost))
+                .collect::<Vec<(u32, u32)>>()
+        };
+
+        assert_eq!(dijkstra([(0, 0)], successors, |state| *state == 3), Some(6));
+        assert_eq!(dijkstra([(0, 0)], successors, |state| *state == 4), None);
+        assert_eq!(
+            dijkstra([(0, 10), (2, 0)], successors, |state| *state == 3),
+            Some(4)
+        );
+    }
+}
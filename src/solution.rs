@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+
+use crate::template::{read_file, Day};
+
+/// A uniform interface to a day's solution, with each answer formatted as a string so that every
+/// day can be driven the same way whatever its answer types are.
+///
+/// The [`solution!`](crate::solution) macro implements this for each day on a `Solver` struct,
+/// by wrapping that day's `part_one` and `part_two` functions.
+pub trait Solution {
+    #[must_use]
+    fn part_one(_input: &str) -> Option<String> {
+        None
+    }
+
+    #[must_use]
+    fn part_two(_input: &str) -> Option<String> {
+        None
+    }
+}
+
+/// An answer along with the wall-clock time taken to calculate it.
+pub type TimedAnswer = (Option<String>, Duration);
+
+fn timed(part: impl FnOnce() -> Option<String>) -> TimedAnswer {
+    let timer = Instant::now();
+    let answer = part();
+    (answer, timer.elapsed())
+}
+
+/// Runs both parts of a solution on `input`, timing each one.
+#[must_use]
+pub fn time_parts<S: Solution>(input: &str) -> (TimedAnswer, TimedAnswer) {
+    (timed(|| S::part_one(input)), timed(|| S::part_two(input)))
+}
+
+/// Runs both parts of a solution on the real input for `day`, timing each one.
+///
+/// # Panics
+///
+/// Will panic if the input file for `day` can't be read.
+#[must_use]
+pub fn solve_timed<S: Solution>(day: Day) -> (TimedAnswer, TimedAnswer) {
+    time_parts::<S>(&read_file("inputs", day))
+}
@@ -47,6 +47,9 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Also creates a `Solver` struct implementing [`Solution`](crate::solution::Solution) for the
+/// parts that are run.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -59,10 +62,21 @@ macro_rules! solution {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $( [$func:ident, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// This day's solution, with answers formatted as strings.
+        pub struct Solver;
+
+        impl $crate::solution::Solution for Solver {
+            $(
+                fn $func(input: &str) -> Option<String> {
+                    $func(input).map(|answer| answer.to_string())
+                }
+            )*
+        }
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;